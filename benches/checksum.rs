@@ -0,0 +1,47 @@
+//! Throughput benchmarks for `update()`, following the bencher-style layout used
+//! by other checksum/byte-order crates in the ecosystem (see lebe, byteorder).
+//!
+//! Run with `cargo bench`. Each benchmark reports ns/iter for a fixed-size buffer,
+//! from which MB/s can be derived.
+
+#[macro_use]
+extern crate bencher;
+extern crate fletcher;
+
+use bencher::{black_box, Bencher};
+use fletcher::{Fletcher16, Fletcher32, Fletcher64};
+
+const BUF_LEN: usize = 1024 * 1024;
+
+fn fletcher16_1mb(bench: &mut Bencher) {
+    let data = vec![0xA5u8; BUF_LEN];
+    bench.bytes = BUF_LEN as u64;
+    bench.iter(|| {
+        let mut checksum = Fletcher16::new();
+        checksum.update(black_box(&data));
+        black_box(checksum.value());
+    });
+}
+
+fn fletcher32_1mb(bench: &mut Bencher) {
+    let data = vec![0xA5A5u16; BUF_LEN / 2];
+    bench.bytes = BUF_LEN as u64;
+    bench.iter(|| {
+        let mut checksum = Fletcher32::new();
+        checksum.update(black_box(&data));
+        black_box(checksum.value());
+    });
+}
+
+fn fletcher64_1mb(bench: &mut Bencher) {
+    let data = vec![0xA5A5_A5A5u32; BUF_LEN / 4];
+    bench.bytes = BUF_LEN as u64;
+    bench.iter(|| {
+        let mut checksum = Fletcher64::new();
+        checksum.update(black_box(&data));
+        black_box(checksum.value());
+    });
+}
+
+benchmark_group!(benches, fletcher16_1mb, fletcher32_1mb, fletcher64_1mb);
+benchmark_main!(benches);
@@ -15,6 +15,25 @@
 //! objects also allow you to initialize them with specific values using the
 //! [`Fletcher::with_initial_values()`] constructor function.
 //!
+//! If all you have is a raw byte buffer, e.g. something read off disk or the network, the
+//! [`Fletcher::update_bytes()`] function will fold successive words in directly without
+//! requiring you to pre-split the buffer into `u16`/`u32` words yourself. Pass the
+//! [`Endianness`] the words are encoded with; a trailing partial word is zero-padded.
+//!
+//! The [`Fletcher::checkpoint()`] and [`Fletcher::from_checkpoint()`] functions let you
+//! save and resume the running sums across non-contiguous buffers, and [`Fletcher::combine()`]
+//! merges the checksums of two adjacent segments, e.g. ones checksummed on separate threads.
+//!
+//! [`Fletcher16`], [`Fletcher32`], and [`Fletcher64`] also implement [`core::hash::Hasher`],
+//! so they can be used anywhere a [`core::hash::Hash`]-deriving type needs to be checksummed,
+//! such as a `HashMap`'s [`core::hash::BuildHasher`].
+//!
+//! ## `no_std`
+//!
+//! This crate is `no_std` by default and requires no allocator, making it usable in firmware
+//! computing checksums over flash/EEPROM. The `std` feature is enabled by default for
+//! convenience; build with `default-features = false` to drop it entirely.
+//!
 //! ## Check Values
 //!
 //! The typical use case for checksums is to generate the value and store it alongside the data,
@@ -28,7 +47,9 @@
 //! The [`checkvalues_fletcher16()`], [`checkvalues_fletcher32()`], and
 //! [`checkvalues_fletcher64()`] functions provide a one-shot means of generating the needed check
 //! vlaues to force the checksum to be zero. Alternatively the [`Fletcher::check_values()`]
-//! function is available if you are using the [`Fletcher`] objects.
+//! (or [`Fletcher::check_words()`], its tuple-returning equivalent) function is available if
+//! you are using the [`Fletcher`] objects, and [`Fletcher::verify()`] checks whether a checksum
+//! that already includes appended check words is valid.
 //!
 //! ## Examples
 //!
@@ -52,7 +73,7 @@
 //! assert_eq!(fletcher::calc_fletcher16(&data), 0);
 //! ```
 
-#![no_std]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(test)]
 #[macro_use]
@@ -69,7 +90,8 @@ use core::{
     cmp::PartialEq,
     convert::{From, TryInto},
     fmt::Debug,
-    ops::{Add, AddAssign, BitAnd, BitOr, Shl, Shr, Sub},
+    hash::Hasher,
+    ops::{Add, AddAssign, BitAnd, BitOr, Mul, Shl, Shr, Sub},
 };
 
 /// Base set of values and operations needed for our implementation
@@ -83,6 +105,7 @@ pub trait FletcherAccumulator:
     + Sub<Output = Self>
     + BitAnd<Output = Self>
     + BitOr<Output = Self>
+    + Mul<Output = Self>
     + Shl<u16, Output = Self>
     + Shr<u16, Output = Self>
     + PartialEq
@@ -105,6 +128,26 @@ pub trait FletcherAccumulator:
     /// of the value into the least significant half of the value. This is
     /// typically half the bit width of the type, i.e. 8 for 16 bit values
     const SHIFT_AMOUNT: u16;
+
+    /// The number of bytes that make up a single [`InputType`](Self::InputType) word,
+    /// i.e. 2 for `u16`
+    const INPUT_WIDTH: usize;
+
+    /// Decodes a single word from a byte slice of exactly
+    /// [`INPUT_WIDTH`](Self::InputType) bytes, using the given [`Endianness`]
+    fn word_from_bytes(bytes: &[u8], endianness: Endianness) -> Self::InputType;
+
+    /// Reduces a word count modulo the block modulus `M` and returns it as an
+    /// accumulator value, for use in [`Fletcher::combine()`]
+    fn from_len(len: usize) -> Self;
+
+    /// The fast path behind [`Fletcher::update()`]
+    ///
+    /// Unlike the naive algorithm, this accumulates into a wider temporary (double
+    /// the width of `Self`) and only performs the modular reduction once per much
+    /// larger block, instead of once per [`MAX_CHUNK_SIZE`](Self::MAX_CHUNK_SIZE)
+    /// words, while still processing the inner loop in unrolled groups of 4.
+    fn update_wide(fletcher: &mut Fletcher<Self>, data: &[Self::InputType]);
 }
 
 impl FletcherAccumulator for u16 {
@@ -112,6 +155,61 @@ impl FletcherAccumulator for u16 {
     const BIT_MASK: Self = 0x00ff;
     const MAX_CHUNK_SIZE: usize = 21;
     const SHIFT_AMOUNT: u16 = 8;
+    const INPUT_WIDTH: usize = 1;
+
+    fn word_from_bytes(bytes: &[u8], _endianness: Endianness) -> Self::InputType {
+        bytes[0]
+    }
+
+    fn from_len(len: usize) -> Self {
+        (len % 0x00ff) as Self
+    }
+
+    fn update_wide(fletcher: &mut Fletcher<Self>, data: &[u8]) {
+        // Safely below the ~5803 words a triangular sum of 0xff-valued bytes
+        // can reach before overflowing a u32.
+        const WIDE_MAX_CHUNK_SIZE: usize = 4096;
+
+        for chunk in data.chunks(WIDE_MAX_CHUNK_SIZE) {
+            let mut wide_a: u32 = u32::from(fletcher.a);
+            let mut wide_b: u32 = u32::from(fletcher.b);
+
+            let mut groups = chunk.chunks_exact(4);
+            for group in &mut groups {
+                wide_a += u32::from(group[0]);
+                wide_b += wide_a;
+                wide_a += u32::from(group[1]);
+                wide_b += wide_a;
+                wide_a += u32::from(group[2]);
+                wide_b += wide_a;
+                wide_a += u32::from(group[3]);
+                wide_b += wide_a;
+            }
+            for element in groups.remainder() {
+                wide_a += u32::from(*element);
+                wide_b += wide_a;
+            }
+
+            // 2^8 === 1 (mod 2^8 - 1), so folding the four 8-bit limbs of the
+            // 32-bit wide accumulator together is equivalent mod M. The result
+            // comfortably fits back in 16 bits, where the usual `reduce()`
+            // finishes canonicalizing it.
+            let fold = |value: u32| -> u16 {
+                let limbs = (value & 0xff)
+                    + ((value >> 8) & 0xff)
+                    + ((value >> 16) & 0xff)
+                    + ((value >> 24) & 0xff);
+                Fletcher::<u16>::reduce(limbs as u16)
+            };
+
+            fletcher.a = fold(wide_a);
+            fletcher.b = fold(wide_b);
+        }
+
+        // One last reduction must be done since we process in chunks
+        fletcher.a = Fletcher::<u16>::reduce(fletcher.a);
+        fletcher.b = Fletcher::<u16>::reduce(fletcher.b);
+    }
 }
 
 impl FletcherAccumulator for u32 {
@@ -119,6 +217,65 @@ impl FletcherAccumulator for u32 {
     const BIT_MASK: Self = 0x0000ffff;
     const MAX_CHUNK_SIZE: usize = 360;
     const SHIFT_AMOUNT: u16 = 16;
+    const INPUT_WIDTH: usize = 2;
+
+    fn word_from_bytes(bytes: &[u8], endianness: Endianness) -> Self::InputType {
+        let word = [bytes[0], bytes[1]];
+        match endianness {
+            Endianness::Big => u16::from_be_bytes(word),
+            Endianness::Little => u16::from_le_bytes(word),
+        }
+    }
+
+    fn from_len(len: usize) -> Self {
+        (len % 0x0000ffff) as Self
+    }
+
+    fn update_wide(fletcher: &mut Fletcher<Self>, data: &[u16]) {
+        // Safely below the ~23.7 million words a triangular sum of
+        // 0xffff-valued words can reach before overflowing a u64.
+        const WIDE_MAX_CHUNK_SIZE: usize = 1_048_576;
+
+        for chunk in data.chunks(WIDE_MAX_CHUNK_SIZE) {
+            let mut wide_a: u64 = u64::from(fletcher.a);
+            let mut wide_b: u64 = u64::from(fletcher.b);
+
+            let mut groups = chunk.chunks_exact(4);
+            for group in &mut groups {
+                wide_a += u64::from(group[0]);
+                wide_b += wide_a;
+                wide_a += u64::from(group[1]);
+                wide_b += wide_a;
+                wide_a += u64::from(group[2]);
+                wide_b += wide_a;
+                wide_a += u64::from(group[3]);
+                wide_b += wide_a;
+            }
+            for element in groups.remainder() {
+                wide_a += u64::from(*element);
+                wide_b += wide_a;
+            }
+
+            // 2^16 === 1 (mod 2^16 - 1), so folding the four 16-bit limbs of
+            // the 64-bit wide accumulator together is equivalent mod M. The
+            // result comfortably fits back in 32 bits, where the usual
+            // `reduce()` finishes canonicalizing it.
+            let fold = |value: u64| -> u32 {
+                let limbs = (value & 0xffff)
+                    + ((value >> 16) & 0xffff)
+                    + ((value >> 32) & 0xffff)
+                    + ((value >> 48) & 0xffff);
+                Fletcher::<u32>::reduce(limbs as u32)
+            };
+
+            fletcher.a = fold(wide_a);
+            fletcher.b = fold(wide_b);
+        }
+
+        // One last reduction must be done since we process in chunks
+        fletcher.a = Fletcher::<u32>::reduce(fletcher.a);
+        fletcher.b = Fletcher::<u32>::reduce(fletcher.b);
+    }
 }
 
 impl FletcherAccumulator for u64 {
@@ -126,8 +283,79 @@ impl FletcherAccumulator for u64 {
     const BIT_MASK: Self = 0x00000000ffffffff;
     const MAX_CHUNK_SIZE: usize = 92680;
     const SHIFT_AMOUNT: u16 = 32;
+    const INPUT_WIDTH: usize = 4;
+
+    fn word_from_bytes(bytes: &[u8], endianness: Endianness) -> Self::InputType {
+        let word = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        match endianness {
+            Endianness::Big => u32::from_be_bytes(word),
+            Endianness::Little => u32::from_le_bytes(word),
+        }
+    }
+
+    fn from_len(len: usize) -> Self {
+        (len % 0x00000000ffffffff) as Self
+    }
+
+    fn update_wide(fletcher: &mut Fletcher<Self>, data: &[u32]) {
+        // Safely below the ~398 trillion words a triangular sum of
+        // 0xffffffff-valued words can reach before overflowing a u128.
+        const WIDE_MAX_CHUNK_SIZE: usize = 16_777_216;
+
+        for chunk in data.chunks(WIDE_MAX_CHUNK_SIZE) {
+            let mut wide_a: u128 = u128::from(fletcher.a);
+            let mut wide_b: u128 = u128::from(fletcher.b);
+
+            let mut groups = chunk.chunks_exact(4);
+            for group in &mut groups {
+                wide_a += u128::from(group[0]);
+                wide_b += wide_a;
+                wide_a += u128::from(group[1]);
+                wide_b += wide_a;
+                wide_a += u128::from(group[2]);
+                wide_b += wide_a;
+                wide_a += u128::from(group[3]);
+                wide_b += wide_a;
+            }
+            for element in groups.remainder() {
+                wide_a += u128::from(*element);
+                wide_b += wide_a;
+            }
+
+            // 2^32 === 1 (mod 2^32 - 1), so folding the four 32-bit limbs of
+            // the 128-bit wide accumulator together is equivalent mod M. The
+            // result comfortably fits back in 64 bits, where the usual
+            // `reduce()` finishes canonicalizing it.
+            let fold = |value: u128| -> u64 {
+                let limbs = (value & 0xffffffff)
+                    + ((value >> 32) & 0xffffffff)
+                    + ((value >> 64) & 0xffffffff)
+                    + ((value >> 96) & 0xffffffff);
+                Fletcher::<u64>::reduce(limbs as u64)
+            };
+
+            fletcher.a = fold(wide_a);
+            fletcher.b = fold(wide_b);
+        }
+
+        // One last reduction must be done since we process in chunks
+        fletcher.a = Fletcher::<u64>::reduce(fletcher.a);
+        fletcher.b = Fletcher::<u64>::reduce(fletcher.b);
+    }
 }
 
+/// Selects how multi-byte words are decoded from a raw buffer by
+/// [`Fletcher::update_bytes()`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    Big,
+    Little,
+}
+
+/// The widest word any [`FletcherAccumulator::InputType`] can be, in bytes.
+/// Used to size the zero-padding buffer for a trailing partial word.
+const MAX_INPUT_WIDTH: usize = 4;
+
 /// Type to hold the state for calculating a fletcher checksum.
 ///
 /// This is useful if you want to calculate the checksum over several small
@@ -141,6 +369,11 @@ where
 {
     a: T,
     b: T,
+
+    /// Bytes left over from a [`Hasher::write()`] call that ended mid-word,
+    /// carried over to the next call
+    pending: [u8; MAX_INPUT_WIDTH],
+    pending_len: u8,
 }
 
 impl<T> Fletcher<T>
@@ -152,6 +385,8 @@ where
         Fletcher {
             a: T::default(),
             b: T::default(),
+            pending: [0; MAX_INPUT_WIDTH],
+            pending_len: 0,
         }
     }
 
@@ -163,17 +398,69 @@ where
         Fletcher {
             a: a.into(),
             b: b.into(),
+            pending: [0; MAX_INPUT_WIDTH],
+            pending_len: 0,
         }
     }
 
+    /// Construct a checksum object resuming from a previously exported pair of
+    /// partial sums
+    ///
+    /// Unlike [`Fletcher::with_initial_values()`], which seeds the checksum with a
+    /// single word-sized value, this takes the full running accumulators as
+    /// exported by [`Fletcher::checkpoint()`]. This lets a checksum be
+    /// checkpointed and resumed later, e.g. block-by-block across a journal
+    /// replay.
+    pub fn from_checkpoint(sum1: T, sum2: T) -> Fletcher<T> {
+        Fletcher {
+            a: sum1,
+            b: sum2,
+            pending: [0; MAX_INPUT_WIDTH],
+            pending_len: 0,
+        }
+    }
+
+    /// Exports the two running partial sums, for checkpointing with
+    /// [`Fletcher::from_checkpoint()`] or merging with [`Fletcher::combine()`]
+    pub fn checkpoint(&self) -> (T, T) {
+        (self.a, self.b)
+    }
+
     /// Updates the checksum with the given input data
+    ///
+    /// This is the deferred-reduction fast path described on
+    /// [`FletcherAccumulator::update_wide()`]: words are accumulated into a temporary twice
+    /// the width of `T`, in unrolled groups of 4, and the modular reduction only runs once
+    /// per much larger block than [`MAX_CHUNK_SIZE`](FletcherAccumulator::MAX_CHUNK_SIZE).
+    /// The result is bit-identical to folding in one word at a time.
     pub fn update(&mut self, data: &[<T as FletcherAccumulator>::InputType]) {
-        for chunk in data.chunks(<T as FletcherAccumulator>::MAX_CHUNK_SIZE) {
+        T::update_wide(self, data);
+    }
+
+    /// Updates the checksum with the given raw bytes, decoding successive
+    /// [`FletcherAccumulator::InputType`] words using the given [`Endianness`]
+    ///
+    /// A trailing partial word, if any, is zero-padded before being folded in,
+    /// matching the behaviour of other Fletcher implementations that operate
+    /// directly on byte buffers.
+    pub fn update_bytes(&mut self, data: &[u8], endianness: Endianness) {
+        let word_width = <T as FletcherAccumulator>::INPUT_WIDTH;
+        let byte_chunk_size = word_width * <T as FletcherAccumulator>::MAX_CHUNK_SIZE;
+
+        for byte_chunk in data.chunks(byte_chunk_size) {
             let mut intermediate_a = self.a;
             let mut intermediate_b = self.b;
 
-            for element in chunk {
-                intermediate_a += (*element).into();
+            for word_bytes in byte_chunk.chunks(word_width) {
+                let word = if word_bytes.len() == word_width {
+                    T::word_from_bytes(word_bytes, endianness)
+                } else {
+                    let mut padded = [0u8; MAX_INPUT_WIDTH];
+                    padded[..word_bytes.len()].copy_from_slice(word_bytes);
+                    T::word_from_bytes(&padded[..word_width], endianness)
+                };
+
+                intermediate_a += word.into();
                 intermediate_b += intermediate_a;
             }
 
@@ -181,14 +468,34 @@ where
             self.b = Self::reduce(intermediate_b);
         }
 
-        // One last reduction must be done since we  process in chunks
+        // One last reduction must be done since we process in chunks
         self.a = Self::reduce(self.a);
         self.b = Self::reduce(self.b);
     }
 
     /// Returns the current checksum value
     pub fn value(&self) -> T {
-        Self::combine(self.a, self.b)
+        Self::join_halves(self.a, self.b)
+    }
+
+    /// Merges this checksum with the checksum of a segment that immediately
+    /// follows it in the original data, without re-reading either segment
+    ///
+    /// `other` must be the checksum of the data that comes directly after
+    /// this checksum's data, and `other_len` is the number of words that were
+    /// fed into `other`. This lets independently computed checksums of
+    /// adjacent segments, e.g. from parallel hashing, be folded into the
+    /// checksum of the concatenated data.
+    pub fn combine(&self, other: &Self, other_len: usize) -> Self {
+        let other_len_mod_m = T::from_len(other_len);
+        let carry = Self::reduce(Self::reduce(self.a * other_len_mod_m));
+
+        Fletcher {
+            a: Self::reduce(self.a + other.a),
+            b: Self::reduce(Self::reduce(self.b + other.b + carry)),
+            pending: [0; MAX_INPUT_WIDTH],
+            pending_len: 0,
+        }
     }
 
     pub fn check_values(&self) -> [T::InputType; 2]
@@ -204,11 +511,32 @@ where
         ]
     }
 
-    /// Combines the two accumulator values into a single value
+    /// Returns the two words that should be appended to the checksummed data
+    /// so that running the checksum over `data || check_words` verifies, i.e.
+    /// protocols like OSPF/BIRD that self-check a frame in a single pass.
+    ///
+    /// This is equivalent to [`Fletcher::check_values()`], but returns a tuple
+    /// rather than an array.
+    pub fn check_words(&self) -> (T::InputType, T::InputType)
+    where
+        <T as TryInto<<T as FletcherAccumulator>::InputType>>::Error: Debug,
+    {
+        let [c0, c1] = self.check_values();
+        (c0, c1)
+    }
+
+    /// Returns `true` if the checksum accumulated so far, including any
+    /// appended [`Fletcher::check_words()`], is the algorithm's check
+    /// constant, i.e. the data has not been corrupted
+    pub fn verify(&self) -> bool {
+        self.value() == T::default()
+    }
+
+    /// Joins the two accumulator values into a single value
     ///
     /// This function assumes that the accumulators have already
     /// been fully reduced.
-    fn combine(lower: T, upper: T) -> T {
+    fn join_halves(lower: T, upper: T) -> T {
         lower | (upper << T::SHIFT_AMOUNT)
     }
 
@@ -226,6 +554,63 @@ where
             result
         }
     }
+
+    /// Feeds bytes through [`Fletcher::update_bytes()`], buffering a leftover
+    /// byte between calls so a word split across two `write()` calls is still
+    /// folded in correctly. Backs the [`Hasher`] impls below.
+    fn hash_write(&mut self, mut bytes: &[u8]) {
+        let word_width = <T as FletcherAccumulator>::INPUT_WIDTH;
+
+        if self.pending_len > 0 {
+            let pending_len = self.pending_len as usize;
+            let needed = word_width - pending_len;
+            let take = needed.min(bytes.len());
+
+            self.pending[pending_len..pending_len + take].copy_from_slice(&bytes[..take]);
+            self.pending_len += take as u8;
+            bytes = &bytes[take..];
+
+            if self.pending_len as usize != word_width {
+                return;
+            }
+
+            let word = T::word_from_bytes(&self.pending[..word_width], Endianness::Little);
+            self.a += word.into();
+            self.b += self.a;
+            self.a = Self::reduce(self.a);
+            self.b = Self::reduce(self.b);
+            self.pending_len = 0;
+        }
+
+        let remainder = bytes.len() % word_width;
+        let (whole_words, leftover) = bytes.split_at(bytes.len() - remainder);
+
+        self.update_bytes(whole_words, Endianness::Little);
+
+        self.pending[..remainder].copy_from_slice(leftover);
+        self.pending_len = remainder as u8;
+    }
+
+    /// Folds in any buffered partial word and returns the resulting checksum,
+    /// without disturbing the live state. Backs the [`Hasher`] impls below.
+    fn hash_finish(&self) -> T {
+        let mut finished = *self;
+
+        if finished.pending_len > 0 {
+            let word_width = <T as FletcherAccumulator>::INPUT_WIDTH;
+            let mut padded = [0u8; MAX_INPUT_WIDTH];
+            padded[..finished.pending_len as usize]
+                .copy_from_slice(&finished.pending[..finished.pending_len as usize]);
+
+            let word = T::word_from_bytes(&padded[..word_width], Endianness::Little);
+            finished.a += word.into();
+            finished.b += finished.a;
+            finished.a = Self::reduce(finished.a);
+            finished.b = Self::reduce(finished.b);
+        }
+
+        finished.value()
+    }
 }
 
 impl<T> Default for Fletcher<T>
@@ -237,6 +622,38 @@ where
     }
 }
 
+/// Lets any [`Hash`](core::hash::Hash)-deriving type be checksummed directly,
+/// e.g. for use as a [`BuildHasher`](core::hash::BuildHasher) in a `HashMap`
+impl Hasher for Fletcher<u16> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.hash_write(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash_finish() as u64
+    }
+}
+
+impl Hasher for Fletcher<u32> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.hash_write(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash_finish() as u64
+    }
+}
+
+impl Hasher for Fletcher<u64> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.hash_write(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash_finish()
+    }
+}
+
 /// Produces a 16-bit checksum from a stream of 8-bit data.
 ///
 /// # Example
@@ -319,6 +736,7 @@ pub fn checkvalues_fletcher64(data: &[u32]) -> [u32; 2] {
 mod test {
     use super::*;
     use byteorder::{ByteOrder, LittleEndian};
+    use core::hash::Hasher;
     use std::vec::Vec;
 
     fn run_test<T>(test_data: &[<T as FletcherAccumulator>::InputType], expected_value: &T)
@@ -404,9 +822,85 @@ mod test {
         }
     }
 
+    #[test]
+    fn fletcher16_check_words_and_verify() {
+        let mut data = vec![0xC1, 0x77, 0xE9, 0xC0, 0xAB, 0x1E];
+
+        let mut checksum = Fletcher16::new();
+        checksum.update(&data);
+
+        let (c0, c1) = checksum.check_words();
+        data.push(c0);
+        data.push(c1);
+
+        let mut verifier = Fletcher16::new();
+        verifier.update(&data);
+        assert!(verifier.verify());
+    }
+
+    #[test]
+    fn fletcher16_checkpoint() {
+        let data = [0xC1, 0x77, 0xE9, 0xC0, 0xAB, 0x1E];
+
+        let mut checksum = Fletcher16::new();
+        checksum.update(&data[0..3]);
+
+        let (sum1, sum2) = checksum.checkpoint();
+        let mut resumed = Fletcher16::from_checkpoint(sum1, sum2);
+
+        checksum.update(&data[3..]);
+        resumed.update(&data[3..]);
+
+        assert_eq!(checksum.value(), resumed.value());
+    }
+
+    #[test]
+    fn fletcher16_combine() {
+        let data = vec![0xC1, 0x77, 0xE9, 0xC0, 0xAB, 0x1E];
+
+        let mut whole = Fletcher16::new();
+        whole.update(&data);
+
+        let mut left = Fletcher16::new();
+        left.update(&data[0..3]);
+
+        let mut right = Fletcher16::new();
+        right.update(&data[3..]);
+
+        let combined = left.combine(&right, data[3..].len());
+
+        assert_eq!(whole.value(), combined.value());
+    }
+
+    #[test]
+    fn fletcher16_hasher() {
+        let data = vec![0xC1, 0x77, 0xE9, 0xC0, 0xAB, 0x1E];
+
+        let mut checksum = Fletcher16::new();
+        checksum.update(&data);
+
+        let mut hasher = Fletcher16::new();
+        hasher.write(&data[0..3]);
+        hasher.write(&data[3..]);
+
+        assert_eq!(checksum.value() as u64, hasher.finish());
+    }
+
+    #[test]
+    fn fletcher16_update_bytes() {
+        let data = vec![0xC1, 0x77, 0xE9, 0xC0, 0xAB, 0x1E];
+
+        let mut checksum = Fletcher16::new();
+        checksum.update(&data);
+
+        let mut byte_checksum = Fletcher16::new();
+        byte_checksum.update_bytes(&data, Endianness::Little);
+
+        assert_eq!(checksum.value(), byte_checksum.value());
+    }
+
     fn convert_bytes_u16(raw_data: &str) -> Vec<u16> {
-        let mut output = Vec::new();
-        output.resize(raw_data.len() / 2, 0);
+        let mut output = vec![0; raw_data.len() / 2];
         LittleEndian::read_u16_into(raw_data.as_bytes(), &mut output);
         output
     }
@@ -485,9 +979,86 @@ mod test {
         }
     }
 
+    #[test]
+    fn fletcher32_check_words_and_verify() {
+        let mut data = vec![0xF02A, 0xCB0D, 0x5639, 0x6501, 0x2384, 0x75BB];
+
+        let mut checksum = Fletcher32::new();
+        checksum.update(&data);
+
+        let (c0, c1) = checksum.check_words();
+        data.push(c0);
+        data.push(c1);
+
+        let mut verifier = Fletcher32::new();
+        verifier.update(&data);
+        assert!(verifier.verify());
+    }
+
+    #[test]
+    fn fletcher32_combine() {
+        let data = vec![0xF02A, 0xCB0D, 0x5639, 0x6501, 0x2384, 0x75BB];
+
+        let mut whole = Fletcher32::new();
+        whole.update(&data);
+
+        let mut left = Fletcher32::new();
+        left.update(&data[0..2]);
+
+        let mut right = Fletcher32::new();
+        right.update(&data[2..]);
+
+        let combined = left.combine(&right, data[2..].len());
+
+        assert_eq!(whole.value(), combined.value());
+    }
+
+    #[test]
+    fn fletcher32_hasher() {
+        let data = convert_bytes_u16("abcdefgh");
+
+        let mut checksum = Fletcher32::new();
+        checksum.update(&data);
+
+        // Split the write so a word lands across two calls, to exercise the
+        // leftover-byte buffering.
+        let mut hasher = Fletcher32::new();
+        hasher.write(&"abcdefgh".as_bytes()[..3]);
+        hasher.write(&"abcdefgh".as_bytes()[3..]);
+
+        assert_eq!(checksum.value() as u64, hasher.finish());
+    }
+
+    #[test]
+    fn fletcher32_update_bytes() {
+        let data = convert_bytes_u16("abcdef");
+
+        let mut checksum = Fletcher32::new();
+        checksum.update(&data);
+
+        let mut byte_checksum = Fletcher32::new();
+        byte_checksum.update_bytes("abcdef".as_bytes(), Endianness::Little);
+
+        assert_eq!(checksum.value(), byte_checksum.value());
+    }
+
+    #[test]
+    fn fletcher32_update_bytes_trailing_partial_word() {
+        // "abcde" is 5 bytes, so the trailing word is zero-padded the same
+        // way "abcde\0" is when pre-split into u16 words.
+        let data = convert_bytes_u16("abcde\0");
+
+        let mut checksum = Fletcher32::new();
+        checksum.update(&data);
+
+        let mut byte_checksum = Fletcher32::new();
+        byte_checksum.update_bytes("abcde".as_bytes(), Endianness::Little);
+
+        assert_eq!(checksum.value(), byte_checksum.value());
+    }
+
     fn convert_bytes_u32(raw_data: &str) -> Vec<u32> {
-        let mut output = Vec::new();
-        output.resize(raw_data.len() / 4, 0);
+        let mut output = vec![0; raw_data.len() / 4];
         LittleEndian::read_u32_into(raw_data.as_bytes(), &mut output);
         output
     }
@@ -559,6 +1130,72 @@ mod test {
         }
     }
 
+    #[test]
+    fn fletcher64_check_words_and_verify() {
+        let mut data: Vec<u32> = vec![
+            0xA0F15604, 0x82856B93, 0xC4395038, 0xF3CAC9CB, 0x39B7C44B, 0xEB0F23DA,
+        ];
+
+        let mut checksum = Fletcher64::new();
+        checksum.update(&data);
+
+        let (c0, c1) = checksum.check_words();
+        data.push(c0);
+        data.push(c1);
+
+        let mut verifier = Fletcher64::new();
+        verifier.update(&data);
+        assert!(verifier.verify());
+    }
+
+    #[test]
+    fn fletcher64_combine() {
+        let data: Vec<u32> = vec![
+            0xA0F15604, 0x82856B93, 0xC4395038, 0xF3CAC9CB, 0x39B7C44B, 0xEB0F23DA,
+        ];
+
+        let mut whole = Fletcher64::new();
+        whole.update(&data);
+
+        let mut left = Fletcher64::new();
+        left.update(&data[0..2]);
+
+        let mut right = Fletcher64::new();
+        right.update(&data[2..]);
+
+        let combined = left.combine(&right, data[2..].len());
+
+        assert_eq!(whole.value(), combined.value());
+    }
+
+    #[test]
+    fn fletcher64_hasher() {
+        let data = convert_bytes_u32("abcdefgh");
+
+        let mut checksum = Fletcher64::new();
+        checksum.update(&data);
+
+        let mut hasher = Fletcher64::new();
+        for byte in "abcdefgh".as_bytes() {
+            hasher.write(&[*byte]);
+        }
+
+        assert_eq!(checksum.value(), hasher.finish());
+    }
+
+    #[test]
+    fn fletcher64_update_bytes() {
+        let data = convert_bytes_u32("abcdefgh");
+
+        let mut checksum = Fletcher64::new();
+        checksum.update(&data);
+
+        let mut byte_checksum = Fletcher64::new();
+        byte_checksum.update_bytes("abcdefgh".as_bytes(), Endianness::Little);
+
+        assert_eq!(checksum.value(), byte_checksum.value());
+    }
+
     #[test]
     fn issue_8() {
         let data = [0x06, 0x83, 0x0d, 0x00, 0xc5, 0x18, 0xe5, 0x08, 0xef, 0x11];